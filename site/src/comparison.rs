@@ -3,18 +3,47 @@
 //! comparison endpoints
 
 use crate::api;
-use crate::db::{self, ArtifactId, Cache, Crate, Profile};
+use crate::db::{self, ArtifactId, Cache, Crate, Metric, Profile};
 use crate::load::InputData;
 use crate::selector::{self, Tag};
 
 use collector::Bound;
 use database::Date;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use std::collections::HashMap;
 use std::error::Error;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+/// Environment variable that, when set, gives the full path of the line-delimited
+/// JSON store that triage runs append their computed comparison summaries to.
+const HISTORY_STORE_ENV: &str = "RUSTC_PERF_COMPARISON_HISTORY";
+/// Environment variable naming the site's on-disk data directory, under which
+/// the history store lives by default.
+const HISTORY_DATA_DIR_ENV: &str = "RUSTC_PERF_DATA_DIR";
+/// Default filename of the history store within the data directory.
+const HISTORY_STORE_FILE: &str = "comparison-history.jsonl";
+/// Upper bound on the number of records kept in the history store; the oldest
+/// are dropped once it grows past this so the file can't grow without limit.
+const HISTORY_STORE_MAX_RECORDS: usize = 10_000;
+
+/// Resolves the path of the comparison history store.
+///
+/// Honors [`HISTORY_STORE_ENV`] when set; otherwise anchors the file under the
+/// data directory named by [`HISTORY_DATA_DIR_ENV`], falling back to the current
+/// directory only when neither is set. Anchoring keeps the store's location
+/// stable regardless of where the site binary happens to be launched.
+fn history_store_path() -> PathBuf {
+    if let Some(path) = std::env::var_os(HISTORY_STORE_ENV) {
+        return PathBuf::from(path);
+    }
+    let dir = std::env::var_os(HISTORY_DATA_DIR_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("."));
+    dir.join(HISTORY_STORE_FILE)
+}
+
 type BoxedError = Box<dyn Error + Send + Sync>;
 
 pub async fn handle_triage(
@@ -23,12 +52,13 @@ pub async fn handle_triage(
 ) -> Result<api::triage::Response, BoxedError> {
     let start = body.start;
     let end = body.end;
+    let stats = body.stats.clone().unwrap_or_else(default_triage_stats);
     // Compare against self to get next
     let master_commits = rustc_artifacts::master_commits().await?;
     let comparison = compare(
         start.clone(),
         start.clone(),
-        "instructions:u".to_owned(),
+        stats.clone(),
         data,
         &master_commits,
     )
@@ -36,13 +66,17 @@ pub async fn handle_triage(
     let mut after = Bound::Commit(comparison.next(&master_commits).unwrap()); // TODO: handle no next commit
 
     let mut report = HashMap::new();
+    let mut rows = Vec::new();
+    let mut tables = Vec::new();
+    let mut stored = Vec::new();
+    let today = chrono::Utc::today().format("%Y-%m-%d").to_string();
     let mut before = start.clone();
 
     loop {
         let comparison = compare(
             before,
             after.clone(),
-            "instructions:u".to_owned(),
+            stats.clone(),
             data,
             &master_commits,
         )
@@ -54,7 +88,20 @@ pub async fn handle_triage(
         );
 
         // handle results of comparison
-        populate_report(&comparison, &mut report).await;
+        populate_report(&comparison, &mut report, &mut rows).await;
+        let full = comparison.full_table();
+        if full.iter().any(|r| r.significant) {
+            tables.push(format!(
+                "{}..{}\n\n{}",
+                comparison.a.commit,
+                comparison.b.commit,
+                render_comparison_table(&full)
+            ));
+        }
+        let record = comparison.to_stored(&today);
+        if !record.benchmarks.is_empty() {
+            stored.push(record);
+        }
 
         // Check that there is a next commit and that the
         // after commit is not equal to `end`
@@ -68,7 +115,25 @@ pub async fn handle_triage(
     }
     let end = end.unwrap_or(after);
 
-    let report = generate_report(&start, &end, report);
+    // Persist this run's summaries so trends can be tracked across runs, then
+    // use the accumulated history to derive follow-up nags.
+    let store = history_store_path();
+    for record in &stored {
+        if let Err(e) = store_comparison(&store, record) {
+            log::warn!("failed to persist comparison history: {}", e);
+        }
+    }
+    if let Err(e) = trim_comparison_history(&store, HISTORY_STORE_MAX_RECORDS) {
+        log::warn!("failed to trim comparison history: {}", e);
+    }
+    let history = load_comparison_history(&store).unwrap_or_default();
+    let month = &today[..today.len().min(7)];
+    let nags = repeated_regressions(&history, month, 2);
+
+    let report = match body.format.unwrap_or(ReportFormat::Markdown) {
+        ReportFormat::Markdown => generate_report(&start, &end, report, &tables, &nags),
+        ReportFormat::Html => generate_html_report(&start, &end, &rows),
+    };
     Ok(api::triage::Response(report))
 }
 
@@ -78,12 +143,13 @@ pub async fn handle_compare(
 ) -> Result<api::days::Response, BoxedError> {
     let commits = rustc_artifacts::master_commits().await?;
     let comparison =
-        crate::comparison::compare(body.start, body.end, body.stat, data, &commits).await?;
+        crate::comparison::compare(body.start, body.end, vec![body.stat], data, &commits).await?;
 
     let conn = data.conn().await;
     let prev = comparison.prev(&commits);
     let next = comparison.next(&commits);
     let is_contiguous = comparison.is_contiguous(&*conn, &commits).await;
+    let full_table = comparison.full_table();
 
     Ok(api::days::Response {
         prev,
@@ -91,19 +157,273 @@ pub async fn handle_compare(
         b: comparison.b,
         next,
         is_contiguous,
+        full_table,
+    })
+}
+
+pub async fn handle_bisect(
+    body: api::bisect::Request,
+    data: &InputData,
+) -> Result<api::bisect::Response, BoxedError> {
+    let master_commits = rustc_artifacts::master_commits().await?;
+    let culprits = bisect(body.start, body.end, body.stat, data, &master_commits).await?;
+    Ok(api::bisect::Response { culprits })
+}
+
+/// The commit that a single (benchmark, cache state) regression was bisected
+/// down to.
+#[derive(Debug, Clone, Serialize)]
+pub struct Culprit {
+    pub bench_name: String,
+    pub cache_state: String,
+    pub commit: String,
+    pub pr: Option<u32>,
+}
+
+/// Binary-searches the `master_commits` history between `start` and `end` to
+/// find, for every (benchmark, cache) pair that is significantly changed over
+/// the full range, the single commit that introduced that change.
+///
+/// Starting from the full comparison we collect the set of significant
+/// (bench, cache) pairs together with the direction of their log-change. We
+/// then repeatedly split the surviving interval at its midpoint, comparing
+/// each half, and follow each pair into whichever half still carries a
+/// significant change in the same direction. Midpoints with no data in the DB
+/// are skipped. Once an interval is contiguous the commit `b` of that interval
+/// is the culprit for every pair that reached it.
+pub async fn bisect(
+    start: Bound,
+    end: Bound,
+    stat: String,
+    data: &InputData,
+    master_commits: &[rustc_artifacts::Commit],
+) -> Result<Vec<Culprit>, BoxedError> {
+    let full = compare(
+        start.clone(),
+        end.clone(),
+        vec![stat.clone()],
+        data,
+        master_commits,
+    )
+    .await?;
+
+    // The (bench, cache) pairs worth bisecting, paired with whether the change
+    // over the full range was an increase.
+    let mut targets = HashMap::new();
+    for c in full.get_benchmarks() {
+        if c.is_significant() {
+            targets.insert(
+                (c.bench_name.to_owned(), c.cache_state.to_owned()),
+                c.is_increase(),
+            );
+        }
+    }
+
+    let mut culprits = HashMap::new();
+    bisect_interval(
+        &full,
+        targets,
+        &stat,
+        data,
+        master_commits,
+        &mut culprits,
+    )
+    .await?;
+
+    let mut culprits = culprits.into_iter().collect::<Vec<_>>();
+    culprits.sort_by(|a, b| a.bench_name.cmp(&b.bench_name));
+    Ok(culprits)
+}
+
+/// Recursively bisects a single interval, assigning a `Culprit` to every
+/// surviving target once the interval is contiguous.
+fn bisect_interval<'a>(
+    comparison: &'a Comparison,
+    targets: HashMap<(String, String), bool>,
+    stat: &'a str,
+    data: &'a InputData,
+    master_commits: &'a [rustc_artifacts::Commit],
+    culprits: &'a mut HashMap<(String, String), Culprit>,
+) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), BoxedError>> + Send + 'a>> {
+    Box::pin(async move {
+        if targets.is_empty() {
+            return Ok(());
+        }
+
+        // Borrow a connection only for the contiguity check and drop it before
+        // recursing, so a bounded pool isn't held across the whole bisection
+        // while the nested `compare` calls each need their own connection.
+        let contiguous = {
+            let conn = data.conn().await;
+            comparison.is_contiguous(&*conn, master_commits).await
+        };
+
+        // Once the interval is a single step, `b` is the commit that
+        // introduced every change that made it this far.
+        if contiguous {
+            for ((bench_name, cache_state), _) in targets {
+                culprits.entry((bench_name.clone(), cache_state.clone())).or_insert(Culprit {
+                    bench_name,
+                    cache_state,
+                    commit: comparison.b.commit.clone(),
+                    pr: comparison.b.pr,
+                });
+            }
+            return Ok(());
+        }
+
+        let has_data = |sha: &str| data.data_for(true, Bound::Commit(sha.to_owned())).is_some();
+        let mid = match midpoint(comparison, master_commits, has_data) {
+            Some(mid) => mid,
+            // No commit strictly between the endpoints with usable data; treat
+            // the later endpoint as the culprit.
+            None => {
+                for ((bench_name, cache_state), _) in targets {
+                    culprits.entry((bench_name.clone(), cache_state.clone())).or_insert(Culprit {
+                        bench_name,
+                        cache_state,
+                        commit: comparison.b.commit.clone(),
+                        pr: comparison.b.pr,
+                    });
+                }
+                return Ok(());
+            }
+        };
+
+        let lower = compare(
+            Bound::Commit(comparison.a.commit.clone()),
+            Bound::Commit(mid.clone()),
+            vec![stat.to_owned()],
+            data,
+            master_commits,
+        )
+        .await?;
+        let upper = compare(
+            Bound::Commit(mid),
+            Bound::Commit(comparison.b.commit.clone()),
+            vec![stat.to_owned()],
+            data,
+            master_commits,
+        )
+        .await?;
+
+        let lower_sig = significant_pairs(&lower);
+        let upper_sig = significant_pairs(&upper);
+
+        let mut in_lower = HashMap::new();
+        let mut in_upper = HashMap::new();
+        for (key, increase) in targets {
+            // Follow the change into the half that still exhibits it in the
+            // same direction, preferring the later half when both do.
+            if upper_sig.get(&key) == Some(&increase) {
+                in_upper.insert(key, increase);
+            } else if lower_sig.get(&key) == Some(&increase) {
+                in_lower.insert(key, increase);
+            } else {
+                // The change doesn't survive into either half (noise); keep
+                // following it into the upper half.
+                in_upper.insert(key, increase);
+            }
+        }
+
+        bisect_interval(&lower, in_lower, stat, data, master_commits, culprits).await?;
+        bisect_interval(&upper, in_upper, stat, data, master_commits, culprits).await?;
+        Ok(())
     })
 }
 
-async fn populate_report(comparison: &Comparison, report: &mut HashMap<Direction, Vec<String>>) {
+/// The significant (bench, cache) pairs of a comparison mapped to whether the
+/// change was an increase.
+fn significant_pairs(comparison: &Comparison) -> HashMap<(String, String), bool> {
+    comparison
+        .get_benchmarks()
+        .into_iter()
+        .filter(|c| c.is_significant())
+        .map(|c| {
+            (
+                (c.bench_name.to_owned(), c.cache_state.to_owned()),
+                c.is_increase(),
+            )
+        })
+        .collect()
+}
+
+/// The sha of the commit closest to halfway between the comparison's endpoints
+/// in the master commit history for which `has_data` holds, or `None` when the
+/// endpoints are adjacent, either endpoint is missing from `master_commits`, or
+/// no commit strictly between them has data.
+fn midpoint(
+    comparison: &Comparison,
+    master_commits: &[rustc_artifacts::Commit],
+    has_data: impl Fn(&str) -> bool,
+) -> Option<String> {
+    let a = master_commits.iter().position(|c| c.sha == comparison.a.commit)?;
+    let b = master_commits.iter().position(|c| c.sha == comparison.b.commit)?;
+    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+    if hi <= lo + 1 {
+        return None;
+    }
+    let mid = (lo + hi) / 2;
+    let mut candidates = (lo + 1..hi).collect::<Vec<_>>();
+    candidates.sort_by_key(|&i| (i as isize - mid as isize).abs());
+    candidates
+        .into_iter()
+        .map(|i| &master_commits[i].sha)
+        .find(|sha| has_data(sha))
+        .cloned()
+}
+
+async fn populate_report(
+    comparison: &Comparison,
+    report: &mut HashMap<Direction, Vec<String>>,
+    rows: &mut Vec<ReportRow>,
+) {
     if let Some(summary) = summarize_comparison(comparison) {
         if let Some(direction) = summary.direction() {
-            let entry = report.entry(direction).or_default();
+            let link = compare_link(&comparison.a.commit, &comparison.b.commit);
+            for change in comparison
+                .get_benchmarks()
+                .into_iter()
+                .filter(|c| c.is_significant())
+            {
+                let (before, after) = change.dominant_results();
+                rows.push(ReportRow {
+                    bench_name: change.bench_name.to_owned(),
+                    cache_state: change.cache_state.to_owned(),
+                    before,
+                    after,
+                    direction: change.direction(),
+                    link: link.clone(),
+                    pr: comparison.b.pr,
+                });
+            }
 
+            let entry = report.entry(direction).or_default();
             entry.push(summary.write(comparison).await)
         }
     }
 }
 
+/// The output format of a triage report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// GitHub-flavored Markdown, suitable for a PR comment.
+    Markdown,
+    /// A standalone, styled HTML document, suitable for a browser or email.
+    Html,
+}
+
+/// A single (benchmark, cache) movement, captured for tabular rendering.
+struct ReportRow {
+    bench_name: String,
+    cache_state: String,
+    before: f64,
+    after: f64,
+    direction: Direction,
+    link: String,
+    pr: Option<u32>,
+}
+
 fn summarize_comparison<'a>(comparison: &'a Comparison) -> Option<ComparisonSummary<'a>> {
     let mut benchmarks = comparison.get_benchmarks();
     // Skip empty commits, sometimes happens if there's a compiler bug or so.
@@ -195,11 +515,29 @@ impl ComparisonSummary<'_> {
     }
 }
 
-/// Compare two bounds on a given stat
+/// The stats triage reports on when the request doesn't specify its own set.
+/// The first entry is the primary metric, which ranks and describes a pair
+/// when none of its metrics moved significantly.
+fn default_triage_stats() -> Vec<String> {
+    vec![
+        "instructions:u".to_owned(),
+        "wall-time".to_owned(),
+        "max-rss".to_owned(),
+        "cycles:u".to_owned(),
+    ]
+}
+
+/// Compare two bounds over a set of stats.
+///
+/// Every stat is reported per benchmark, and each pair is ranked by its most
+/// significantly changed stat, so that memory or wall-time regressions the
+/// instruction count misses are surfaced. The first stat in `stats` is the
+/// primary metric (historically `instructions:u`), used for ranking only when
+/// no stat of a pair moved significantly.
 pub async fn compare(
     start: Bound,
     end: Bound,
-    stat: String,
+    stats: Vec<String>,
     data: &InputData,
     master_commits: &[rustc_artifacts::Commit],
 ) -> Result<Comparison, BoxedError> {
@@ -211,11 +549,16 @@ pub async fn compare(
         .ok_or(format!("could not find end commit for bound {:?}", end))?;
     let cids = Arc::new(vec![a.clone().into(), b.clone().into()]);
 
+    let primary_metric = stats
+        .first()
+        .cloned()
+        .ok_or("at least one stat must be requested")?;
+
     let query = selector::Query::new()
         .set::<String>(Tag::Crate, selector::Selector::All)
         .set::<String>(Tag::Cache, selector::Selector::All)
         .set::<String>(Tag::Profile, selector::Selector::All)
-        .set(Tag::ProcessStatistic, selector::Selector::One(stat.clone()));
+        .set(Tag::ProcessStatistic, selector::Selector::Subset(stats));
 
     let mut responses = data.query::<Option<f64>>(query, cids).await?;
 
@@ -226,6 +569,7 @@ pub async fn compare(
         a_id: a,
         b: DateData::consume_one(&*conn, b.clone(), &mut responses, master_commits).await,
         b_id: b,
+        primary_metric,
     })
 }
 
@@ -235,7 +579,8 @@ pub struct DateData {
     pub date: Option<Date>,
     pub pr: Option<u32>,
     pub commit: String,
-    pub data: HashMap<String, Vec<(String, f64)>>,
+    // "crate-profile" -> cache state -> metric -> value
+    pub data: HashMap<String, HashMap<String, HashMap<String, f64>>>,
     // crate -> nanoseconds
     pub bootstrap: HashMap<String, u64>,
 }
@@ -250,7 +595,7 @@ impl DateData {
     where
         T: Iterator<Item = (db::ArtifactId, Option<f64>)>,
     {
-        let mut data = HashMap::new();
+        let mut data: HashMap<String, HashMap<String, HashMap<String, f64>>> = HashMap::new();
 
         for response in series {
             let (id, point) = response.series.next().expect("must have element");
@@ -261,13 +606,18 @@ impl DateData {
             } else {
                 continue;
             };
-            data.entry(format!(
+            let bench = format!(
                 "{}-{}",
                 response.path.get::<Crate>().unwrap(),
                 response.path.get::<Profile>().unwrap(),
-            ))
-            .or_insert_with(Vec::new)
-            .push((response.path.get::<Cache>().unwrap().to_string(), point));
+            );
+            let cache = response.path.get::<Cache>().unwrap().to_string();
+            let metric = response.path.get::<Metric>().unwrap().to_string();
+            data.entry(bench)
+                .or_default()
+                .entry(cache)
+                .or_default()
+                .insert(metric, point);
         }
 
         let bootstrap = conn.get_bootstrap(&[conn.artifact_id(&commit).await]).await;
@@ -320,6 +670,9 @@ pub struct Comparison {
     pub a: DateData,
     pub b_id: ArtifactId,
     pub b: DateData,
+    /// The stat used for ranking a pair when none of its stats moved
+    /// significantly.
+    pub primary_metric: String,
 }
 
 impl Comparison {
@@ -370,13 +723,22 @@ impl Comparison {
                 continue;
             }
             if let Some(b) = self.b.data.get(bench_name) {
-                for (cache_state, a) in a.iter() {
-                    if let Some(b) = b.iter().find(|(cs, _)| cs == cache_state).map(|(_, b)| b) {
-                        result.push(BenchmarkComparison {
-                            bench_name,
-                            cache_state,
-                            results: (a.clone(), b.clone()),
-                        })
+                for (cache_state, a_metrics) in a.iter() {
+                    if let Some(b_metrics) = b.get(cache_state) {
+                        let mut results = HashMap::new();
+                        for (metric, av) in a_metrics.iter() {
+                            if let Some(bv) = b_metrics.get(metric) {
+                                results.insert(metric.clone(), (*av, *bv));
+                            }
+                        }
+                        if !results.is_empty() {
+                            result.push(BenchmarkComparison {
+                                bench_name,
+                                cache_state,
+                                results,
+                                primary: &self.primary_metric,
+                            })
+                        }
                     }
                 }
             }
@@ -384,50 +746,374 @@ impl Comparison {
 
         result
     }
+
+    /// Every (benchmark, cache, metric) movement in this comparison as
+    /// structured rows, sorted by descending magnitude of log-change. Unlike
+    /// [`summarize_comparison`] this keeps the full distribution rather than
+    /// only the single largest regression and improvement.
+    pub fn full_table(&self) -> Vec<ComparisonRow> {
+        let mut rows = Vec::new();
+        for c in self.get_benchmarks() {
+            for (metric, &(before, after)) in c.results.iter() {
+                // A zero baseline has no meaningful ratio — dividing would yield
+                // `inf`/`NaN` that sorts unpredictably and renders as `NaN%` —
+                // so report no relative movement while still showing the delta.
+                let percent = percent_change(before, after);
+                let log_change = if before == 0.0 {
+                    0.0
+                } else {
+                    (after / before).ln()
+                };
+                rows.push(ComparisonRow {
+                    bench_name: c.bench_name.to_owned(),
+                    cache_state: c.cache_state.to_owned(),
+                    metric: metric.clone(),
+                    before,
+                    after,
+                    delta: after - before,
+                    percent,
+                    log_change,
+                    significant: c.is_significant_of(metric),
+                });
+            }
+        }
+        rows.sort_by(|a, b| {
+            b.log_change
+                .abs()
+                .partial_cmp(&a.log_change.abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        rows
+    }
+
+    /// A persistable summary of this comparison's significant movements,
+    /// stamped with the triage run's `date`.
+    pub fn to_stored(&self, date: &str) -> StoredComparison {
+        let benchmarks = self
+            .get_benchmarks()
+            .into_iter()
+            .filter(|c| c.is_significant())
+            .map(|c| {
+                let (before, after) = c.dominant_results();
+                StoredBenchmark {
+                    bench_name: c.bench_name.to_owned(),
+                    cache_state: c.cache_state.to_owned(),
+                    pr: self.b.pr,
+                    before,
+                    after,
+                    log_change: c.log_change(),
+                    direction: c.direction().to_string(),
+                    significant: true,
+                }
+            })
+            .collect();
+        StoredComparison {
+            date: date.to_owned(),
+            start: self.a.commit.clone(),
+            end: self.b.commit.clone(),
+            benchmarks,
+        }
+    }
+}
+
+/// A triage run's significant comparisons, as persisted to the history store.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredComparison {
+    /// Date of the triage run (`YYYY-MM-DD`).
+    pub date: String,
+    /// Start commit sha of the revision range.
+    pub start: String,
+    /// End commit sha of the revision range.
+    pub end: String,
+    pub benchmarks: Vec<StoredBenchmark>,
+}
+
+/// A single persisted (benchmark, cache) movement.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredBenchmark {
+    pub bench_name: String,
+    pub cache_state: String,
+    pub pr: Option<u32>,
+    pub before: f64,
+    pub after: f64,
+    pub log_change: f64,
+    pub direction: String,
+    pub significant: bool,
+}
+
+/// Appends a triage run's comparison summary to the line-delimited JSON
+/// history store, creating it if it does not yet exist.
+pub fn store_comparison(path: &Path, comparison: &StoredComparison) -> std::io::Result<()> {
+    use std::io::Write;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    let line = serde_json::to_string(comparison).expect("StoredComparison is serializable");
+    writeln!(file, "{}", line)
+}
+
+/// Enforces the `max_records` bound on the history store by rewriting it with
+/// only the most recent records once it has grown past the cap. A store within
+/// the bound (or a missing one) is left untouched.
+pub fn trim_comparison_history(path: &Path, max_records: usize) -> std::io::Result<()> {
+    use std::io::Write;
+    let history = load_comparison_history(path)?;
+    if history.len() <= max_records {
+        return Ok(());
+    }
+    let keep = &history[history.len() - max_records..];
+    let mut file = std::fs::File::create(path)?;
+    for record in keep {
+        let line = serde_json::to_string(record).expect("StoredComparison is serializable");
+        writeln!(file, "{}", line)?;
+    }
+    Ok(())
+}
+
+/// Reads every stored comparison back from the history store. A missing store
+/// is treated as an empty history; malformed lines are skipped.
+pub fn load_comparison_history(path: &Path) -> std::io::Result<Vec<StoredComparison>> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    Ok(contents
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .filter_map(|l| serde_json::from_str(l).ok())
+        .collect())
+}
+
+/// A single historical movement of one benchmark across stored triage runs.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkHistoryEntry {
+    pub date: String,
+    pub start: String,
+    pub end: String,
+    pub cache_state: String,
+    pub pr: Option<u32>,
+    pub log_change: f64,
+    pub direction: String,
+}
+
+/// The change history of a single benchmark across stored triage runs, ordered
+/// by run date (most recent last, matching append order).
+pub fn benchmark_history(
+    history: &[StoredComparison],
+    bench_name: &str,
+) -> Vec<BenchmarkHistoryEntry> {
+    let mut entries = Vec::new();
+    for run in history {
+        for b in &run.benchmarks {
+            if b.bench_name == bench_name {
+                entries.push(BenchmarkHistoryEntry {
+                    date: run.date.clone(),
+                    start: run.start.clone(),
+                    end: run.end.clone(),
+                    cache_state: b.cache_state.clone(),
+                    pr: b.pr,
+                    log_change: b.log_change,
+                    direction: b.direction.clone(),
+                });
+            }
+        }
+    }
+    entries
+}
+
+/// Benchmarks that have regressed at least `threshold` times during `month`
+/// (formatted `YYYY-MM`), paired with their regression count and ordered most
+/// frequent first -- the basis for the follow-up nag section.
+pub fn repeated_regressions(
+    history: &[StoredComparison],
+    month: &str,
+    threshold: usize,
+) -> Vec<(String, usize)> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for run in history {
+        if !run.date.starts_with(month) {
+            continue;
+        }
+        for b in &run.benchmarks {
+            if b.direction == "regression" {
+                *counts.entry(b.bench_name.clone()).or_default() += 1;
+            }
+        }
+    }
+    let mut nags = counts
+        .into_iter()
+        .filter(|(_, n)| *n >= threshold)
+        .collect::<Vec<_>>();
+    nags.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    nags
 }
 
-// A single comparison based on benchmark and cache state
+/// A single (benchmark, cache, metric) row in the full comparison table.
+#[derive(Debug, Clone, Serialize)]
+pub struct ComparisonRow {
+    pub bench_name: String,
+    pub cache_state: String,
+    pub metric: String,
+    pub before: f64,
+    pub after: f64,
+    pub delta: f64,
+    pub percent: f64,
+    pub log_change: f64,
+    pub significant: bool,
+}
+
+/// Renders a set of [`ComparisonRow`]s as an aligned Markdown table.
+pub fn render_comparison_table(rows: &[ComparisonRow]) -> String {
+    use std::fmt::Write;
+
+    let mut out = String::from(
+        "| Benchmark | Cache | Metric | Before | After | Δ | % | Log | Significant |\n\
+         |---|---|---|--:|--:|--:|--:|--:|:-:|\n",
+    );
+    for row in rows {
+        writeln!(
+            out,
+            "| {} | {} | {} | {:.0} | {:.0} | {:+.0} | {:+.2}% | {:+.4} | {} |",
+            row.bench_name,
+            row.cache_state,
+            row.metric,
+            row.before,
+            row.after,
+            row.delta,
+            row.percent,
+            row.log_change,
+            if row.significant { "yes" } else { "noise" },
+        )
+        .unwrap();
+    }
+    out
+}
+
+// A single comparison based on benchmark and cache state, holding the
+// before/after values for every requested metric.
 #[derive(Debug)]
 struct BenchmarkComparison<'a> {
     bench_name: &'a str,
     cache_state: &'a str,
-    results: (f64, f64),
+    // metric -> (before, after)
+    results: HashMap<String, (f64, f64)>,
+    // The fallback metric for ranking and single-value summaries when no
+    // metric moved significantly.
+    primary: &'a str,
 }
 
 const SIGNIFICANCE_THRESHOLD: f64 = 0.01;
 impl BenchmarkComparison<'_> {
-    fn log_change(&self) -> f64 {
-        let (a, b) = self.results;
+    /// The primary metric, falling back to any available metric if the primary
+    /// one has no data for this pair. Used by the scalar summary accessors
+    /// (via [`Self::dominant_metric`]) when no metric moved significantly.
+    fn primary_metric(&self) -> &str {
+        if self.results.contains_key(self.primary) {
+            self.primary
+        } else {
+            self.results
+                .keys()
+                .next()
+                .map(|s| s.as_str())
+                .unwrap_or(self.primary)
+        }
+    }
+
+    fn log_change_of(&self, metric: &str) -> f64 {
+        let (a, b) = self.results[metric];
         (b / a).ln()
     }
 
-    fn is_increase(&self) -> bool {
-        let (a, b) = self.results;
+    fn is_increase_of(&self, metric: &str) -> bool {
+        let (a, b) = self.results[metric];
         b > a
     }
 
-    fn is_significant(&self) -> bool {
+    fn relative_change_of(&self, metric: &str) -> f64 {
+        let (a, b) = self.results[metric];
+        (b - a) / a
+    }
+
+    fn is_significant_of(&self, metric: &str) -> bool {
         // This particular (benchmark, cache) combination frequently varies
         if self.bench_name.starts_with("coercions-debug")
             && self.cache_state == "incr-patched: println"
         {
-            self.relative_change().abs() > 2.0
+            self.relative_change_of(metric).abs() > 2.0
+        } else {
+            self.log_change_of(metric).abs() > SIGNIFICANCE_THRESHOLD
+        }
+    }
+
+    fn direction_of(&self, metric: &str) -> Direction {
+        if self.log_change_of(metric) > 0.0 {
+            Direction::Regression
         } else {
-            self.log_change().abs() > SIGNIFICANCE_THRESHOLD
+            Direction::Improvement
         }
     }
 
+    /// The significant metric with the largest magnitude of change, used to
+    /// rank and describe this (benchmark, cache) pair so a row that moved only
+    /// on a secondary metric (e.g. max-rss) is slotted and summarized by that
+    /// metric rather than by its ~flat primary. Falls back to the primary
+    /// metric when nothing is significant so the scalar accessors stay defined.
+    fn dominant_metric(&self) -> &str {
+        self.results
+            .keys()
+            .filter(|m| self.is_significant_of(m))
+            .max_by(|a, b| {
+                self.log_change_of(a)
+                    .abs()
+                    .partial_cmp(&self.log_change_of(b).abs())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|s| s.as_str())
+            .unwrap_or_else(|| self.primary_metric())
+    }
+
+    fn log_change(&self) -> f64 {
+        self.log_change_of(self.dominant_metric())
+    }
+
+    fn is_increase(&self) -> bool {
+        self.is_increase_of(self.dominant_metric())
+    }
+
     fn relative_change(&self) -> f64 {
-        let (a, b) = self.results;
-        (b - a) / a
+        self.relative_change_of(self.dominant_metric())
     }
 
+    /// Whether any requested metric moved significantly.
+    fn is_significant(&self) -> bool {
+        self.results.keys().any(|m| self.is_significant_of(m))
+    }
+
+    /// The overall direction across all significant metrics: `Mixed` when they
+    /// disagree (e.g. instructions drop but max-rss rises), otherwise the
+    /// shared direction. Falls back to the primary metric when nothing is
+    /// significant.
     fn direction(&self) -> Direction {
-        if self.log_change() > 0.0 {
-            Direction::Regression
-        } else {
-            Direction::Improvement
+        let mut overall = None;
+        for metric in self.results.keys() {
+            if self.is_significant_of(metric) {
+                let d = self.direction_of(metric);
+                match overall {
+                    None => overall = Some(d),
+                    Some(prev) if prev != d => return Direction::Mixed,
+                    _ => {}
+                }
+            }
         }
+        overall.unwrap_or_else(|| self.direction_of(self.primary_metric()))
+    }
+
+    /// The before/after values of the metric that ranks and describes this
+    /// pair, so the reported magnitude matches the summarized movement.
+    fn dominant_results(&self) -> (f64, f64) {
+        self.results[self.dominant_metric()]
     }
 
     fn summary_line(&self, summary: &mut String, link: &str) {
@@ -448,9 +1134,10 @@ impl BenchmarkComparison<'_> {
         let percent = self.relative_change() * 100.0;
         write!(
             summary,
-            "{} {} in [instruction counts]({})",
+            "{} {} in [{}]({})",
             size,
             self.direction(),
+            self.dominant_metric(),
             link
         )
         .unwrap();
@@ -464,7 +1151,7 @@ impl BenchmarkComparison<'_> {
 }
 
 // The direction of a performance change
-#[derive(PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum Direction {
     Improvement,
     Regression,
@@ -486,6 +1173,8 @@ fn generate_report(
     start: &Bound,
     end: &Bound,
     mut report: HashMap<Direction, Vec<String>>,
+    tables: &[String],
+    nags: &[(String, usize)],
 ) -> String {
     fn fmt_bound(bound: &Bound) -> String {
         match bound {
@@ -499,6 +1188,19 @@ fn generate_report(
     let regressions = report.remove(&Direction::Regression).unwrap_or_default();
     let improvements = report.remove(&Direction::Improvement).unwrap_or_default();
     let mixed = report.remove(&Direction::Mixed).unwrap_or_default();
+    let full_comparison = if tables.is_empty() {
+        String::new()
+    } else {
+        format!("\n#### Full comparison\n\n{}\n", tables.join("\n"))
+    };
+    let nags = if nags.is_empty() {
+        "TODO: Nags".to_owned()
+    } else {
+        nags.iter()
+            .map(|(bench, n)| format!("- `{}` has regressed {} times this month", bench, n))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
     format!(
         r#####"# {date} Triage Log
 
@@ -521,10 +1223,10 @@ Revision range: [{first_commit}..{last_commit}](https://perf.rust-lang.org/?star
 #### Mixed
 
 {mixed}
-
+{full_comparison}
 #### Nags requiring follow up
 
-TODO: Nags
+{nags}
 
 "#####,
         date = chrono::Utc::today().format("%Y-%m-%d"),
@@ -536,6 +1238,95 @@ TODO: Nags
         regressions = regressions.join("\n\n"),
         improvements = improvements.join("\n\n"),
         mixed = mixed.join("\n\n"),
+        full_comparison = full_comparison,
+        nags = nags,
+    )
+}
+
+/// Percent change from `before` to `after`, guarding the zero baseline that
+/// would otherwise yield `inf`/`NaN`. A zero baseline has no meaningful ratio,
+/// so it reports no relative movement. Shared by the full table and the HTML
+/// report so the two can't drift on this.
+fn percent_change(before: f64, after: f64) -> f64 {
+    if before == 0.0 {
+        0.0
+    } else {
+        (after - before) / before * 100.0
+    }
+}
+
+/// Renders the collected rows as a standalone, styled HTML document with one
+/// row per (benchmark, cache) movement. Unlike the Markdown report this is
+/// self-contained -- the embedded `<style>` block means it renders correctly
+/// in a browser or email client without the GitHub comment pipeline.
+fn generate_html_report(start: &Bound, end: &Bound, rows: &[ReportRow]) -> String {
+    use std::fmt::Write;
+
+    fn fmt_bound(bound: &Bound) -> String {
+        match bound {
+            Bound::Commit(s) => s.to_owned(),
+            Bound::Date(s) => s.format("%Y-%m-%d").to_string(),
+            _ => "???".to_owned(),
+        }
+    }
+    let start = fmt_bound(start);
+    let end = fmt_bound(end);
+
+    let mut body = String::new();
+    for row in rows {
+        let percent = percent_change(row.before, row.after);
+        let pr = match row.pr {
+            Some(pr) => format!(
+                "<a href=\"https://github.com/rust-lang/rust/issues/{pr}\">#{pr}</a>",
+                pr = pr
+            ),
+            None => "-".to_owned(),
+        };
+        write!(
+            body,
+            "<tr><td>{bench}</td><td>{cache}</td>\
+             <td class=\"num\">{before:.0}</td><td class=\"num\">{after:.0}</td>\
+             <td class=\"num\">{percent:+.2}%</td><td>{direction}</td>\
+             <td><a href=\"{link}\">compare</a> {pr}</td></tr>",
+            bench = row.bench_name,
+            cache = row.cache_state,
+            before = row.before,
+            after = row.after,
+            percent = percent,
+            direction = row.direction,
+            link = row.link,
+            pr = pr,
+        )
+        .unwrap();
+    }
+
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{start}..{end} Triage Log</title>
+<style>
+body {{ font-family: sans-serif; margin: 2em; }}
+table {{ border-collapse: collapse; width: 100%; }}
+th, td {{ border: 1px solid #ccc; padding: 4px 8px; }}
+th {{ background: #eee; text-align: left; }}
+tr:nth-child(even) {{ background: #f6f6f6; }}
+td.num {{ text-align: right; font-variant-numeric: tabular-nums; }}
+</style>
+</head>
+<body>
+<h1>{start}..{end} Triage Log</h1>
+<table>
+<tr><th>Benchmark</th><th>Cache state</th><th>Before</th><th>After</th><th>% change</th><th>Direction</th><th>Commit</th></tr>
+{body}
+</table>
+</body>
+</html>
+"#,
+        start = start,
+        end = end,
+        body = body,
     )
 }
 
@@ -28,13 +28,14 @@ use crate::load::SiteCtxt;
 
 use async_trait::async_trait;
 use collector::Bound;
+use futures::stream::{self, Stream, StreamExt};
 use database::{Benchmark, Commit, Index, Lookup, Metric, QueryLabel};
 
 use std::convert::TryInto;
 use std::fmt;
 use std::ops::RangeInclusive;
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 /// Finds the most appropriate `ArtifactId` for a given bound.
 ///
@@ -113,6 +114,68 @@ impl Iterator for ArtifactIdIter {
     }
 }
 
+/// A display-unit conversion applied to a metric's raw points.
+///
+/// Metrics are stored in whatever unit rustc/perf emit them in; this lets a
+/// metric declare how its values should be normalized for display without the
+/// selector code special-casing metric names.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Conversion {
+    /// Leave points unchanged.
+    Identity,
+    /// Multiply every point by a fixed factor (e.g. ms->s is `Scale(1e-3)`).
+    Scale(f64),
+    /// Nanoseconds to seconds.
+    NanosToSeconds,
+    /// Raw byte counts, left unchanged but named for clarity.
+    Bytes,
+}
+
+impl Conversion {
+    /// Applies the conversion to a single optional point.
+    pub fn apply(self, point: Option<f64>) -> Option<f64> {
+        point.map(|v| match self {
+            Conversion::Identity | Conversion::Bytes => v,
+            Conversion::Scale(factor) => v * factor,
+            Conversion::NanosToSeconds => v * 1e-9,
+        })
+    }
+}
+
+impl FromStr for Conversion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "identity" => Conversion::Identity,
+            "ms->s" => Conversion::Scale(1e-3),
+            "ns->s" => Conversion::NanosToSeconds,
+            "bytes" => Conversion::Bytes,
+            other => return Err(format!("unknown conversion {:?}", other)),
+        })
+    }
+}
+
+/// Display-unit metadata for raw metrics, keyed by metric name. Each entry's
+/// spec is parsed through [`Conversion::from_str`], so the unit a metric is
+/// *declared* in drives normalization instead of the selector special-casing
+/// individual names on the request path. Metrics absent from the table are left
+/// unconverted.
+const METRIC_CONVERSIONS: &[(&str, &str)] = &[
+    // perf reports the *-clock metrics in milliseconds; display them in seconds.
+    ("cpu-clock", "ms->s"),
+    ("task-clock", "ms->s"),
+];
+
+/// The display-unit conversion declared for a given metric.
+fn conversion(metric: &Metric) -> Conversion {
+    METRIC_CONVERSIONS
+        .iter()
+        .find(|&&(name, _)| *metric == *name)
+        .map(|&(_, spec)| spec.parse().expect("METRIC_CONVERSIONS holds valid specs"))
+        .unwrap_or(Conversion::Identity)
+}
+
 #[derive(Copy, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Tag {
     Benchmark,
@@ -198,11 +261,48 @@ pub struct QueryComponent {
     pub raw: Selector<String>,
 }
 
+/// Shell-style wildcard match supporting `*` (any run of characters) and `?`
+/// (any single character). Used by [`Selector::Glob`].
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pat = pattern.as_bytes();
+    let txt = text.as_bytes();
+    // Two-pointer backtracking match; `star`/`mark` remember the last `*` and
+    // the text position to resume from if the tentative match fails.
+    let (mut p, mut t) = (0, 0);
+    let mut star: Option<usize> = None;
+    let mut mark = 0;
+    while t < txt.len() {
+        if p < pat.len() && pat[p] != b'*' && (pat[p] == b'?' || pat[p] == txt[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pat.len() && pat[p] == b'*' {
+            star = Some(p);
+            mark = t;
+            p += 1;
+        } else if let Some(sp) = star {
+            p = sp + 1;
+            mark += 1;
+            t = mark;
+        } else {
+            return false;
+        }
+    }
+    while p < pat.len() && pat[p] == b'*' {
+        p += 1;
+    }
+    p == pat.len()
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Selector<T> {
     All,
     Subset(Vec<T>),
     One(T),
+    /// Shell-style pattern (`*`/`?`) matched against the string form of the
+    /// component.
+    Glob(String),
+    /// Matches whenever the inner selector does not.
+    Not(Box<Selector<T>>),
 }
 
 impl<T> Selector<T> {
@@ -211,6 +311,8 @@ impl<T> Selector<T> {
             Selector::All => Selector::All,
             Selector::Subset(subset) => Selector::Subset(subset.into_iter().map(f).collect()),
             Selector::One(o) => Selector::One(f(o)),
+            Selector::Glob(g) => Selector::Glob(g),
+            Selector::Not(inner) => Selector::Not(Box::new(inner.map(f))),
         }
     }
     fn try_map<U, E>(self, mut f: impl FnMut(T) -> Result<U, E>) -> Result<Selector<U>, E> {
@@ -220,17 +322,21 @@ impl<T> Selector<T> {
                 Selector::Subset(subset.into_iter().map(f).collect::<Result<_, _>>()?)
             }
             Selector::One(o) => Selector::One(f(o)?),
+            Selector::Glob(g) => Selector::Glob(g),
+            Selector::Not(inner) => Selector::Not(Box::new(inner.try_map(f)?)),
         })
     }
 
     fn matches<U>(&self, other: U) -> bool
     where
-        U: PartialEq<T>,
+        U: PartialEq<T> + ToString,
     {
         match self {
             Selector::One(c) => other == *c,
             Selector::Subset(subset) => subset.iter().any(|c| other == *c),
             Selector::All => true,
+            Selector::Glob(pattern) => glob_match(pattern, &other.to_string()),
+            Selector::Not(inner) => !inner.matches(other),
         }
     }
 
@@ -246,6 +352,219 @@ impl<T> Selector<T> {
     }
 }
 
+/// A compact growable bitset over series positions, backed by 64-bit words.
+#[derive(Clone, Debug)]
+pub struct BitVector {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitVector {
+    /// An all-zero bitset able to hold `len` positions.
+    pub fn new(len: usize) -> Self {
+        BitVector {
+            words: vec![0; (len + 63) / 64],
+            len,
+        }
+    }
+
+    /// An all-ones bitset covering every position in `0..len`.
+    pub fn ones(len: usize) -> Self {
+        let nwords = (len + 63) / 64;
+        let mut words = vec![u64::MAX; nwords];
+        if len % 64 != 0 {
+            // Clear the bits past `len` in the final word.
+            words[nwords - 1] = (1u64 << (len % 64)) - 1;
+        }
+        BitVector { words, len }
+    }
+
+    /// Marks position `idx` as set.
+    pub fn set(&mut self, idx: usize) {
+        self.words[idx / 64] |= 1u64 << (idx % 64);
+    }
+
+    /// Sets `self` to the union of `self` and `other`.
+    pub fn union_in_place(&mut self, other: &BitVector) {
+        for (a, b) in self.words.iter_mut().zip(other.words.iter()) {
+            *a |= *b;
+        }
+    }
+
+    /// Sets `self` to the intersection of `self` and `other`.
+    pub fn intersect_in_place(&mut self, other: &BitVector) {
+        for (a, b) in self.words.iter_mut().zip(other.words.iter()) {
+            *a &= *b;
+        }
+    }
+
+    /// Iterates the indices of the set bits in ascending order.
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        let len = self.len;
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(|(wi, &word)| {
+                (0..64).filter_map(move |b| {
+                    if word & (1u64 << b) != 0 {
+                        Some(wi * 64 + b)
+                    } else {
+                        None
+                    }
+                })
+            })
+            .filter(move |&i| i < len)
+    }
+}
+
+/// An inverted index over a sorted series list, mapping each distinct value of
+/// a dimension to a bitset of the positions containing it. Filtering a query
+/// becomes a handful of word-wise unions and intersections rather than a scan
+/// of every series for every query.
+///
+/// The benchmark/profile/scenario dimensions are shared by every series family;
+/// the fourth dimension `D` is the metric (pstats) or query label (self
+/// profile). Built from the loaded `Index` and cached per loaded `Index` by
+/// [`InvertedIndexCache`], so it is rebuilt whenever `index` reloads.
+pub struct SeriesInvertedIndex<D: std::hash::Hash + Eq> {
+    len: usize,
+    benchmark: std::collections::HashMap<String, BitVector>,
+    profile: std::collections::HashMap<Profile, BitVector>,
+    scenario: std::collections::HashMap<Scenario, BitVector>,
+    last: std::collections::HashMap<D, BitVector>,
+}
+
+impl<D: std::hash::Hash + Eq + Clone> SeriesInvertedIndex<D> {
+    /// Builds the inverted index from the sorted series list.
+    pub fn build(series: &[(Benchmark, Profile, Scenario, D)]) -> Self {
+        use std::collections::HashMap;
+        let len = series.len();
+        let mut index = SeriesInvertedIndex {
+            len,
+            benchmark: HashMap::new(),
+            profile: HashMap::new(),
+            scenario: HashMap::new(),
+            last: HashMap::new(),
+        };
+        for (pos, s) in series.iter().enumerate() {
+            index
+                .benchmark
+                .entry(s.0.to_string())
+                .or_insert_with(|| BitVector::new(len))
+                .set(pos);
+            index
+                .profile
+                .entry(s.1)
+                .or_insert_with(|| BitVector::new(len))
+                .set(pos);
+            index
+                .scenario
+                .entry(s.2)
+                .or_insert_with(|| BitVector::new(len))
+                .set(pos);
+            index
+                .last
+                .entry(s.3.clone())
+                .or_insert_with(|| BitVector::new(len))
+                .set(pos);
+        }
+        index
+    }
+
+    /// Resolves a query to the positions of the matching series, or `None` when
+    /// any dimension uses a `Glob`/`Not` selector that can't be answered by
+    /// exact-value lookup and the caller should fall back to a linear scan.
+    pub fn resolve(
+        &self,
+        benchmark: &Selector<String>,
+        profile: &Selector<Profile>,
+        scenario: &Selector<Scenario>,
+        last: &Selector<D>,
+    ) -> Option<Vec<usize>> {
+        let mut acc = BitVector::ones(self.len);
+        acc.intersect_in_place(&resolve_dimension(&self.benchmark, benchmark, self.len)?);
+        acc.intersect_in_place(&resolve_dimension(&self.profile, profile, self.len)?);
+        acc.intersect_in_place(&resolve_dimension(&self.scenario, scenario, self.len)?);
+        acc.intersect_in_place(&resolve_dimension(&self.last, last, self.len)?);
+        Some(acc.iter_ones().collect())
+    }
+}
+
+/// Resolves a single dimension's selector to the bitset of matching positions,
+/// returning `None` for selectors that can't be indexed by exact value.
+fn resolve_dimension<K: std::hash::Hash + Eq>(
+    map: &std::collections::HashMap<K, BitVector>,
+    selector: &Selector<K>,
+    len: usize,
+) -> Option<BitVector> {
+    match selector {
+        Selector::All => Some(BitVector::ones(len)),
+        Selector::One(v) => Some(map.get(v).cloned().unwrap_or_else(|| BitVector::new(len))),
+        Selector::Subset(vs) => {
+            let mut bv = BitVector::new(len);
+            for v in vs {
+                if let Some(other) = map.get(v) {
+                    bv.union_in_place(other);
+                }
+            }
+            Some(bv)
+        }
+        Selector::Glob(_) | Selector::Not(_) => None,
+    }
+}
+
+/// The sorted series list paired with its inverted index, cached together so a
+/// query resolves positions against the index and reads the matching series
+/// straight out of the same vector.
+type CachedSeries<D> = Arc<(Vec<(Benchmark, Profile, Scenario, D)>, SeriesInvertedIndex<D>)>;
+
+/// A memoized [`CachedSeries`] keyed on the identity of the loaded `Arc<Index>`.
+///
+/// The series list and inverted index are pure functions of the loaded `Index`,
+/// so they only need rebuilding when the `Index` reloads — which swaps in a
+/// fresh `Arc`. We retain that `Arc` alongside the cached data and compare
+/// identity with [`Arc::ptr_eq`]: keeping the allocation alive means its address
+/// can't be reused by a later `Index`, so a reload is always observed rather
+/// than aliased to a stale entry. The O(series·dimensions) build runs only on
+/// the first request after each reload; every other request only runs `resolve`.
+struct InvertedIndexCache<D> {
+    inner: Mutex<Option<(Arc<Index>, CachedSeries<D>)>>,
+}
+
+impl<D: std::hash::Hash + Eq + Ord + Clone> InvertedIndexCache<D> {
+    const fn new() -> Self {
+        InvertedIndexCache {
+            inner: Mutex::new(None),
+        }
+    }
+
+    /// Returns the cached series/index for `index`, rebuilding it from `build`
+    /// only when `index` differs from the cached one (i.e. on reload).
+    fn get(
+        &self,
+        index: &Arc<Index>,
+        build: impl FnOnce() -> Vec<(Benchmark, Profile, Scenario, D)>,
+    ) -> CachedSeries<D> {
+        let mut guard = self.inner.lock().unwrap();
+        if let Some((cached_index, cached)) = guard.as_ref() {
+            if Arc::ptr_eq(cached_index, index) {
+                return cached.clone();
+            }
+        }
+        let mut all = build();
+        all.sort_unstable();
+        let inverted = SeriesInvertedIndex::build(&all);
+        let cached: CachedSeries<D> = Arc::new((all, inverted));
+        *guard = Some((index.clone(), cached.clone()));
+        cached
+    }
+}
+
+/// Cached pstat series list + inverted index, rebuilt only when `Index` reloads.
+static PSTAT_INVERTED_INDEX: InvertedIndexCache<Metric> = InvertedIndexCache::new();
+/// Cached self-profile query series + inverted index, rebuilt only on reload.
+static QUERY_INVERTED_INDEX: InvertedIndexCache<QueryLabel> = InvertedIndexCache::new();
+
 #[derive(Debug)]
 pub struct SeriesResponse<T> {
     pub path: Path,
@@ -382,6 +701,15 @@ impl Query {
     }
 }
 
+/// A stream of per-series responses, emitted as each series' rows are fetched.
+pub type SeriesStream<'a, E> = std::pin::Pin<
+    Box<
+        dyn Stream<Item = Result<SeriesResponse<Box<dyn Iterator<Item = (ArtifactId, E)> + Send + 'a>>, String>>
+            + Send
+            + 'a,
+    >,
+>;
+
 #[async_trait]
 pub trait SeriesElement: Sized {
     async fn query<'a>(
@@ -389,6 +717,23 @@ pub trait SeriesElement: Sized {
         artifact_ids: Arc<Vec<ArtifactId>>,
         query: Query,
     ) -> Result<Vec<SeriesResponse<Box<dyn Iterator<Item = (ArtifactId, Self)> + Send + 'a>>>, String>;
+
+    /// Non-buffering variant of [`query`] that yields each `SeriesResponse` as
+    /// soon as it is available rather than after the whole set completes.
+    ///
+    /// The default replays the buffering `query`; element types backed by a
+    /// genuinely incremental source can override this to backpressure the DB
+    /// fetches for memory-sensitive callers (e.g. CSV/JSON export).
+    async fn query_stream<'a>(
+        ctxt: &'a SiteCtxt,
+        artifact_ids: Arc<Vec<ArtifactId>>,
+        query: Query,
+    ) -> SeriesStream<'a, Self> {
+        match Self::query(ctxt, artifact_ids, query).await {
+            Ok(responses) => stream::iter(responses.into_iter().map(Ok)).boxed(),
+            Err(e) => stream::once(async move { Err(e) }).boxed(),
+        }
+    }
 }
 
 fn handle_results<'a, E>(
@@ -498,8 +843,32 @@ impl SeriesElement for Option<f64> {
         Vec<SeriesResponse<Box<dyn Iterator<Item = (ArtifactId, Option<f64>)> + Send + 'a>>>,
         String,
     > {
-        let results = vec![
-            ProcessStatisticSeries::expand_query(artifact_ids.clone(), ctxt, query.clone())
+        let mut results = vec![ProcessStatisticSeries::expand_query(
+            artifact_ids.clone(),
+            ctxt,
+            query.clone(),
+        )
+        .await
+        .map(|sr| {
+            sr.into_iter()
+                .map(|sr| {
+                    sr.map(|r| {
+                        Box::new(r) as Box<dyn Iterator<Item = (ArtifactId, Option<f64>)> + Send>
+                    })
+                })
+                .collect()
+        })];
+
+        // Every `QueryDatum`-derived metric is driven through the same generic
+        // series; metric selection is data in `SELF_PROFILE_METRICS`.
+        for descriptor in SELF_PROFILE_METRICS {
+            results.push(
+                SelfProfileMetricSeries::expand_query(
+                    artifact_ids.clone(),
+                    ctxt,
+                    descriptor,
+                    query.clone(),
+                )
                 .await
                 .map(|sr| {
                     sr.into_iter()
@@ -511,7 +880,11 @@ impl SeriesElement for Option<f64> {
                         })
                         .collect()
                 }),
-            SelfProfileQueryTime::expand_query(artifact_ids.clone(), ctxt, query.clone())
+            );
+        }
+
+        results.push(
+            SelfProfileIncrementalTime::expand_query(artifact_ids.clone(), ctxt, query.clone())
                 .await
                 .map(|sr| {
                     sr.into_iter()
@@ -523,10 +896,31 @@ impl SeriesElement for Option<f64> {
                         })
                         .collect()
                 }),
-        ];
+        );
 
         handle_results(results)
     }
+
+    async fn query_stream<'a>(
+        ctxt: &'a SiteCtxt,
+        artifact_ids: Arc<Vec<ArtifactId>>,
+        query: Query,
+    ) -> SeriesStream<'a, Option<f64>> {
+        // The pstat family is the large, memory-sensitive one, so stream it
+        // with per-series DB fetches that the consumer backpressures. The
+        // self-profile families are keyed by query label and have no
+        // incremental source; when the query targets them, defer to the
+        // buffering path (their row counts are small).
+        match ProcessStatisticSeries::expand_query_stream(artifact_ids.clone(), ctxt, query.clone())
+            .await
+        {
+            Ok(stream) => stream,
+            Err(_) => match Self::query(ctxt, artifact_ids, query).await {
+                Ok(responses) => stream::iter(responses.into_iter().map(Ok)).boxed(),
+                Err(e) => stream::once(async move { Err(e) }).boxed(),
+            },
+        }
+    }
 }
 
 impl SiteCtxt {
@@ -536,7 +930,24 @@ impl SiteCtxt {
         artifact_ids: Arc<Vec<ArtifactId>>,
     ) -> Result<Vec<SeriesResponse<Box<dyn Iterator<Item = (ArtifactId, E)> + Send + 'a>>>, String>
     {
-        E::query(self, artifact_ids, query).await
+        // Thin adapter over the streaming variant: collect every response.
+        let mut stream = self.query_stream::<E>(query, artifact_ids).await;
+        let mut responses = Vec::new();
+        while let Some(response) = stream.next().await {
+            responses.push(response?);
+        }
+        Ok(responses)
+    }
+
+    /// Streaming counterpart of [`query`] that emits each series' response as
+    /// soon as it is ready, letting callers consume results without buffering
+    /// the whole set in memory.
+    pub async fn query_stream<'a, E: SeriesElement>(
+        &'a self,
+        query: Query,
+        artifact_ids: Arc<Vec<ArtifactId>>,
+    ) -> SeriesStream<'a, E> {
+        E::query_stream(self, artifact_ids, query).await
     }
 }
 
@@ -562,18 +973,38 @@ impl ProcessStatisticSeries {
         let statid = query.extract_as::<Metric>(Tag::Metric)?;
         query.assert_empty()?;
 
-        let index = ctxt.index.load();
-        let mut series = index
-            .all_pstat_series()
-            .filter(|tup| {
-                krate.matches(tup.0)
-                    && profile.matches(tup.1)
-                    && cache.matches(tup.2)
-                    && statid.matches(tup.3)
-            })
-            .collect::<Vec<_>>();
+        // Incremental-compilation phases share the (crate, profile, scenario,
+        // metric) shape but are served by `SelfProfileIncrementalTime`; defer
+        // to it so a single expander succeeds.
+        if let Selector::One(metric) = &statid {
+            if is_incremental_phase(&metric.to_string()) {
+                return Err(format!(
+                    "{} is an incremental-compilation phase",
+                    metric.to_string()
+                ));
+            }
+        }
 
-        series.sort_unstable();
+        let index = ctxt.index.load_full();
+        // The inverted index is cached on the loaded `Index` and rebuilt only
+        // when it reloads, so the request path resolves against it rather than
+        // rebuilding it; Glob/Not selectors fall back to a linear scan of the
+        // same cached series list.
+        let cached = PSTAT_INVERTED_INDEX.get(&index, || index.all_pstat_series().collect());
+        let (all, inverted) = (&cached.0, &cached.1);
+        let series = match inverted.resolve(&krate, &profile, &cache, &statid) {
+            Some(positions) => positions.into_iter().map(|i| all[i]).collect::<Vec<_>>(),
+            None => all
+                .iter()
+                .copied()
+                .filter(|tup| {
+                    krate.matches(tup.0)
+                        && profile.matches(tup.1)
+                        && cache.matches(tup.2)
+                        && statid.matches(tup.3)
+                })
+                .collect::<Vec<_>>(),
+        };
 
         let sids = series
             .iter()
@@ -607,16 +1038,13 @@ impl ProcessStatisticSeries {
                 SeriesResponse {
                     series: ProcessStatisticSeries {
                         artifact_ids: ArtifactIdIter::new(artifact_ids.clone()),
-                        points: if path.3 == *"cpu-clock" {
-                            // Convert to seconds -- perf reports this measurement in
-                            // milliseconds
+                        points: {
+                            let conversion = conversion(&path.3);
                             points
                                 .into_iter()
-                                .map(|p| p.map(|v| v / 1000.0))
+                                .map(|p| conversion.apply(p))
                                 .collect::<Vec<_>>()
                                 .into_iter()
-                        } else {
-                            points.into_iter()
                         },
                     },
                     path: Path::new()
@@ -635,6 +1063,115 @@ impl ProcessStatisticSeries {
         );
         Ok(res)
     }
+
+    /// Streaming counterpart of [`expand_query`]: resolves the matching series
+    /// without touching the database, then yields one `SeriesResponse` per
+    /// series, fetching that series' rows only as the consumer pulls it. A
+    /// slow consumer therefore backpressures the per-series DB fetches instead
+    /// of buffering every series' points up front.
+    ///
+    /// Returns `Err` (without yielding) when the query isn't a pstat query, so
+    /// the caller can defer to the other series families.
+    async fn expand_query_stream<'a>(
+        artifact_ids: Arc<Vec<ArtifactId>>,
+        ctxt: &'a SiteCtxt,
+        mut query: Query,
+    ) -> Result<SeriesStream<'a, Option<f64>>, String> {
+        let krate = query.extract_as::<String>(Tag::Benchmark)?;
+        let profile = query.extract_as::<Profile>(Tag::Profile)?;
+        let cache = query.extract_as::<Scenario>(Tag::Scenario)?;
+        let statid = query.extract_as::<Metric>(Tag::Metric)?;
+        query.assert_empty()?;
+
+        if let Selector::One(metric) = &statid {
+            if is_incremental_phase(&metric.to_string()) {
+                return Err(format!(
+                    "{} is an incremental-compilation phase",
+                    metric.to_string()
+                ));
+            }
+        }
+
+        let index = ctxt.index.load_full();
+        let cached = PSTAT_INVERTED_INDEX.get(&index, || index.all_pstat_series().collect());
+        let (all, inverted) = (&cached.0, &cached.1);
+        let series = match inverted.resolve(&krate, &profile, &cache, &statid) {
+            Some(positions) => positions.into_iter().map(|i| all[i]).collect::<Vec<_>>(),
+            None => all
+                .iter()
+                .copied()
+                .filter(|tup| {
+                    krate.matches(tup.0)
+                        && profile.matches(tup.1)
+                        && cache.matches(tup.2)
+                        && statid.matches(tup.3)
+                })
+                .collect::<Vec<_>>(),
+        };
+
+        let sids = series
+            .iter()
+            .map(|path| {
+                let query = crate::db::DbLabel::ProcessStat {
+                    benchmark: path.0,
+                    profile: path.1,
+                    scenario: path.2,
+                    metric: path.3,
+                };
+                query.lookup(&index).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let aids = artifact_ids
+            .iter()
+            .map(|aid| aid.lookup(&index))
+            .collect::<Vec<_>>();
+
+        let stream = stream::unfold(
+            (0usize, series, sids, aids, artifact_ids, index, ctxt),
+            move |(i, series, sids, aids, artifact_ids, index, ctxt)| async move {
+                if i >= series.len() {
+                    return None;
+                }
+                // Fetch exactly this series' rows; the await here is what the
+                // consumer's demand gates.
+                let mut conn = ctxt.conn().await;
+                let mut tx = conn.transaction().await;
+                let points = tx
+                    .conn()
+                    .get_pstats(&sids[i..=i], &aids)
+                    .await
+                    .into_iter()
+                    .next()
+                    .expect("get_pstats yields one row set per sid");
+                drop(tx);
+
+                let path = series[i];
+                let conversion = conversion(&path.3);
+                let iter = ProcessStatisticSeries {
+                    artifact_ids: ArtifactIdIter::new(artifact_ids.clone()),
+                    points: points
+                        .into_iter()
+                        .map(|p| conversion.apply(p))
+                        .collect::<Vec<_>>()
+                        .into_iter(),
+                };
+                let response = SeriesResponse {
+                    series: Box::new(iter)
+                        as Box<dyn Iterator<Item = (ArtifactId, Option<f64>)> + Send + 'a>,
+                    path: Path::new()
+                        .set(PathComponent::Crate(path.0))
+                        .set(PathComponent::Profile(path.1))
+                        .set(PathComponent::Cache(path.2))
+                        .set(PathComponent::ProcessStatistic(path.3)),
+                };
+                Some((
+                    Ok(response),
+                    (i + 1, series, sids, aids, artifact_ids, index, ctxt),
+                ))
+            },
+        );
+        Ok(stream.boxed())
+    }
 }
 
 impl Iterator for ProcessStatisticSeries {
@@ -764,12 +1301,108 @@ impl SelfProfile {
     }
 }
 
-pub struct SelfProfileQueryTime {
+/// The incremental-compilation phases surfaced by [`SelfProfileIncrementalTime`].
+const INCREMENTAL_PHASES: &[&str] = &[
+    "dep-graph-encode",
+    "dep-graph-decode",
+    "on-disk-cache-load",
+];
+
+/// Whether `metric` names an incremental-compilation phase rather than a
+/// per-query or process statistic.
+fn is_incremental_phase(metric: &str) -> bool {
+    INCREMENTAL_PHASES.contains(&metric)
+}
+
+/// Extracts the `Metric` selector from a self-profile query and ensures it
+/// names exactly `expected`, so that the metric-specific self-profile series
+/// (invocation counts, cache-hit rate, ...) only expand for their own metric
+/// and a single expander succeeds per query.
+fn require_self_profile_metric(query: &mut Query, expected: &str) -> Result<(), String> {
+    let metric = query.extract_as::<String>(Tag::Metric)?;
+    if let Selector::One(name) = &metric {
+        if name == expected {
+            return Ok(());
+        }
+    }
+    Err(format!(
+        "self-profile metric must be exactly {:?}, got {:?}",
+        expected, metric
+    ))
+}
+
+/// Describes one `QueryDatum`-derived self-profile metric: the `Metric` tag a
+/// query must carry to select it (or `None` for the per-query time series,
+/// which carries no metric tag), and how to pull its point out of the
+/// measureme datum for a single artifact.
+///
+/// Metrics are data rather than types: adding one means appending a descriptor
+/// to [`SELF_PROFILE_METRICS`] instead of writing another near-identical
+/// `expand_query`. This mirrors how rustc moved its per-query plumbing from
+/// macro-unrolled impls to function pointers stored in a descriptor.
+pub struct ProfileMetricDescriptor {
+    metric: Option<&'static str>,
+    extract: fn(&crate::db::QueryDatum) -> Option<f64>,
+    /// Display-unit normalization applied to the extracted point, so the same
+    /// conversion machinery governs both pstat and self-profile series.
+    conversion: Conversion,
+}
+
+/// Every self-profile metric backed by a query's measureme datum, driven
+/// through the single generic [`SelfProfileMetricSeries::expand_query`].
+const SELF_PROFILE_METRICS: &[ProfileMetricDescriptor] = &[
+    ProfileMetricDescriptor {
+        metric: None,
+        extract: |qd| Some(qd.self_time.as_secs_f64()),
+        conversion: Conversion::Identity,
+    },
+    ProfileMetricDescriptor {
+        metric: Some("query-invocation-count"),
+        extract: |qd| Some(qd.invocation_count as f64),
+        conversion: Conversion::Identity,
+    },
+    ProfileMetricDescriptor {
+        metric: Some("query-cache-hit-rate"),
+        // Cache-hit rate is hits / invocations; a query that never fired has no
+        // meaningful ratio, so emit an absent point rather than dividing by zero.
+        extract: |qd| {
+            if qd.invocation_count == 0 {
+                None
+            } else {
+                Some(qd.number_of_cache_hits as f64 / qd.invocation_count as f64)
+            }
+        },
+        conversion: Conversion::Identity,
+    },
+    ProfileMetricDescriptor {
+        metric: Some("query-blocked-time"),
+        // Only the parallel front-end records blocked intervals; a zero/absent
+        // interval means no contention was measured, so emit an absent point
+        // rather than a zero that would make every non-parallel build look
+        // contention-free instead of unmeasured.
+        extract: |qd| {
+            let blocked = qd.blocked_time.as_secs_f64();
+            if blocked == 0.0 {
+                None
+            } else {
+                Some(blocked)
+            }
+        },
+        conversion: Conversion::Identity,
+    },
+];
+
+/// A self-profile series whose per-artifact points come from a
+/// [`ProfileMetricDescriptor`]'s extractor. One generic series type backs every
+/// `QueryDatum`-derived metric (per-query time, invocation counts, cache-hit
+/// rate, ...) so the index filtering, sorting, and `Path` construction live in
+/// a single place.
+pub struct SelfProfileMetricSeries {
     artifact_ids: ArtifactIdIter,
     points: std::vec::IntoIter<Option<f64>>,
 }
 
-impl SelfProfileQueryTime {
+impl SelfProfileMetricSeries {
     async fn new(
         artifact_ids: Arc<Vec<ArtifactId>>,
         ctxt: &SiteCtxt,
@@ -777,6 +1410,8 @@ impl SelfProfileQueryTime {
         profile: Profile,
         cache: Scenario,
         query: QueryLabel,
+        extract: fn(&crate::db::QueryDatum) -> Option<f64>,
+        conversion: Conversion,
     ) -> Self {
         let mut res = Vec::with_capacity(artifact_ids.len());
         let idx = ctxt.index.load();
@@ -792,18 +1427,18 @@ impl SelfProfileQueryTime {
             let point = idx
                 .get::<crate::db::QueryDatum>(tx.conn(), &query, aid)
                 .await
-                .map(|qd| qd.self_time.as_secs_f64());
-            res.push(point);
+                .and_then(|qd| extract(&qd));
+            res.push(conversion.apply(point));
         }
         tx.finish().await.unwrap();
-        SelfProfileQueryTime {
+        SelfProfileMetricSeries {
             artifact_ids: ArtifactIdIter::new(artifact_ids),
             points: res.into_iter(),
         }
     }
 }
 
-impl Iterator for SelfProfileQueryTime {
+impl Iterator for SelfProfileMetricSeries {
     type Item = (ArtifactId, Option<f64>);
     fn next(&mut self) -> Option<Self::Item> {
         Some((self.artifact_ids.next()?, self.points.next().unwrap()))
@@ -814,52 +1449,191 @@ impl Iterator for SelfProfileQueryTime {
     }
 }
 
-impl Series for SelfProfileQueryTime {
+impl Series for SelfProfileMetricSeries {
     type Element = Option<f64>;
 }
 
-impl SelfProfileQueryTime {
+impl SelfProfileMetricSeries {
     async fn expand_query(
         artifact_ids: Arc<Vec<ArtifactId>>,
         ctxt: &SiteCtxt,
+        descriptor: &ProfileMetricDescriptor,
         mut query: Query,
     ) -> Result<Vec<SeriesResponse<Self>>, String> {
         let krate = query.extract_as::<String>(Tag::Benchmark)?;
         let profile = query.extract_as::<Profile>(Tag::Profile)?;
         let cache = query.extract_as::<Scenario>(Tag::Scenario)?;
         let ql = query.extract_as::<QueryLabel>(Tag::QueryLabel)?;
+        // Gate on the metric tag so exactly one descriptor expands a query: the
+        // time series carries no metric tag, the others require their own.
+        if let Some(expected) = descriptor.metric {
+            require_self_profile_metric(&mut query, expected)?;
+        }
         query.assert_empty()?;
 
-        let index = ctxt.index.load();
-        let mut series = index
+        let index = ctxt.index.load_full();
+        // As with pstats, the inverted index is cached on the loaded `Index`
+        // and rebuilt only on reload; the request path just resolves against it.
+        let cached = QUERY_INVERTED_INDEX.get(&index, || index.all_query_series().collect());
+        let (all, inverted) = (&cached.0, &cached.1);
+        let series = match inverted.resolve(&krate, &profile, &cache, &ql) {
+            Some(positions) => positions.into_iter().map(|i| all[i]).collect::<Vec<_>>(),
+            None => all
+                .iter()
+                .copied()
+                .filter(|tup| {
+                    krate.matches(tup.0)
+                        && profile.matches(tup.1)
+                        && cache.matches(tup.2)
+                        && ql.matches(tup.3)
+                })
+                .collect::<Vec<_>>(),
+        };
+
+        let mut res = Vec::with_capacity(series.len());
+        for path in series {
+            res.push(SeriesResponse {
+                series: SelfProfileMetricSeries::new(
+                    artifact_ids.clone(),
+                    ctxt,
+                    path.0,
+                    path.1,
+                    path.2,
+                    path.3,
+                    descriptor.extract,
+                    descriptor.conversion,
+                )
+                .await,
+                path: Path::new()
+                    .set(PathComponent::Crate(path.0))
+                    .set(PathComponent::Profile(path.1))
+                    .set(PathComponent::Cache(path.2))
+                    .set(PathComponent::QueryLabel(path.3)),
+            });
+        }
+        Ok(res)
+    }
+}
+
+/// Timing for the incremental-compilation phases (dep-graph encode/decode and
+/// on-disk-cache load) that rustc records as generic-activity events in the
+/// self-profile stream. Keyed by crate/profile/scenario with the phase in the
+/// `Metric` tag, so regressions in incremental rebuild overhead can be tracked
+/// on their own rather than hidden inside aggregate times.
+pub struct SelfProfileIncrementalTime {
+    artifact_ids: ArtifactIdIter,
+    points: std::vec::IntoIter<Option<f64>>,
+}
+
+impl SelfProfileIncrementalTime {
+    async fn new(
+        artifact_ids: Arc<Vec<ArtifactId>>,
+        ctxt: &SiteCtxt,
+        krate: Benchmark,
+        profile: Profile,
+        cache: Scenario,
+        phase: &str,
+    ) -> Self {
+        let mut res = Vec::with_capacity(artifact_ids.len());
+        let idx = ctxt.index.load();
+        let mut conn = ctxt.conn().await;
+        let mut tx = conn.transaction().await;
+        for aid in artifact_ids.iter() {
+            let artifact_row_id = if let Some(a) = aid.lookup(&idx) {
+                a
+            } else {
+                res.push(None);
+                continue;
+            };
+            let point = tx
+                .conn()
+                .get_self_profile_incremental(
+                    artifact_row_id,
+                    krate.as_str(),
+                    &profile.to_string(),
+                    &cache.to_string(),
+                    phase,
+                )
+                .await
+                .map(|d| d.as_secs_f64());
+            res.push(point);
+        }
+        tx.finish().await.unwrap();
+        SelfProfileIncrementalTime {
+            artifact_ids: ArtifactIdIter::new(artifact_ids),
+            points: res.into_iter(),
+        }
+    }
+}
+
+impl Iterator for SelfProfileIncrementalTime {
+    type Item = (ArtifactId, Option<f64>);
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((self.artifact_ids.next()?, self.points.next().unwrap()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.artifact_ids.size_hint()
+    }
+}
+
+impl Series for SelfProfileIncrementalTime {
+    type Element = Option<f64>;
+}
+
+impl SelfProfileIncrementalTime {
+    async fn expand_query(
+        artifact_ids: Arc<Vec<ArtifactId>>,
+        ctxt: &SiteCtxt,
+        mut query: Query,
+    ) -> Result<Vec<SeriesResponse<Self>>, String> {
+        let krate = query.extract_as::<String>(Tag::Benchmark)?;
+        let profile = query.extract_as::<Profile>(Tag::Profile)?;
+        let cache = query.extract_as::<Scenario>(Tag::Scenario)?;
+        let metric = query.extract_as::<String>(Tag::Metric)?;
+        query.assert_empty()?;
+
+        let phase = match &metric {
+            Selector::One(name) if is_incremental_phase(name) => name.clone(),
+            _ => {
+                return Err(format!(
+                    "incremental timing requires an incremental-compilation phase, got {:?}",
+                    metric
+                ))
+            }
+        };
+        let phase_metric = phase
+            .parse::<Metric>()
+            .map_err(|e| format!("failed to parse phase {:?}: {}", phase, e))?;
+
+        let mut series = ctxt
+            .index
+            .load()
             .all_query_series()
-            .filter(|tup| {
-                krate.matches(tup.0)
-                    && profile.matches(tup.1)
-                    && cache.matches(tup.2)
-                    && ql.matches(tup.3)
-            })
+            .filter(|tup| krate.matches(tup.0) && profile.matches(tup.1) && cache.matches(tup.2))
+            .map(|tup| (tup.0, tup.1, tup.2))
             .collect::<Vec<_>>();
 
         series.sort_unstable();
+        series.dedup();
 
         let mut res = Vec::with_capacity(series.len());
         for path in series {
             res.push(SeriesResponse {
-                series: SelfProfileQueryTime::new(
+                series: SelfProfileIncrementalTime::new(
                     artifact_ids.clone(),
                     ctxt,
                     path.0,
                     path.1,
                     path.2,
-                    path.3,
+                    &phase,
                 )
                 .await,
                 path: Path::new()
                     .set(PathComponent::Crate(path.0))
                     .set(PathComponent::Profile(path.1))
                     .set(PathComponent::Cache(path.2))
-                    .set(PathComponent::QueryLabel(path.3)),
+                    .set(PathComponent::ProcessStatistic(phase_metric.clone())),
             });
         }
         Ok(res)